@@ -63,6 +63,44 @@ pub struct Details {
     pub index: c_uint,
     pub is_up: bool,
     pub mtu: Option<Mtu>,
+    /// The currently active `can_ctrlmode` flags, see the `CAN_CTRLMODE_*` constants.
+    pub ctrlmode: Option<u32>,
+    /// The current CAN bus state.
+    pub state: Option<CanState>,
+    /// The current `(tx_errors, rx_errors)` error counters.
+    pub error_counters: Option<(u16, u16)>,
+    /// The currently configured automatic restart delay in milliseconds, `0` if
+    /// automatic restart is disabled.
+    pub restart_ms: Option<u32>,
+    /// The currently active termination resistance in Ohm, on controllers that
+    /// support switching it.
+    pub termination: Option<u16>,
+}
+
+/// Mirrors the kernel's `enum can_state` from linux/can/netlink.h.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CanState {
+    ErrorActive = 0,
+    ErrorWarning,
+    ErrorPassive,
+    BusOff,
+    Stopped,
+    Sleeping,
+}
+
+impl CanState {
+    fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::ErrorActive),
+            1 => Some(Self::ErrorWarning),
+            2 => Some(Self::ErrorPassive),
+            3 => Some(Self::BusOff),
+            4 => Some(Self::Stopped),
+            5 => Some(Self::Sleeping),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -72,6 +110,39 @@ pub enum Mtu {
     Fd = 72,
 }
 
+/// Flags for the `mask`/`flags` arguments of [`CanInterface::set_ctrlmode`] and the
+/// `ctrlmode` field of [`Details`], from linux/can/netlink.h.
+///
+/// These live here, rather than alongside the other kernel ABI stand-ins in `mod rt`,
+/// because `rt` is private: callers outside this file (and this file's own doctests)
+/// could never have named `rt::CAN_CTRLMODE_LOOPBACK`.
+pub const CAN_CTRLMODE_LOOPBACK: u32 = 1 << 0;
+pub const CAN_CTRLMODE_LISTENONLY: u32 = 1 << 1;
+pub const CAN_CTRLMODE_3_SAMPLES: u32 = 1 << 2;
+pub const CAN_CTRLMODE_ONE_SHOT: u32 = 1 << 3;
+pub const CAN_CTRLMODE_BERR_REPORTING: u32 = 1 << 4;
+pub const CAN_CTRLMODE_FD: u32 = 1 << 5;
+pub const CAN_CTRLMODE_FD_NON_ISO: u32 = 1 << 6;
+pub const CAN_CTRLMODE_PRESUME_ACK: u32 = 1 << 7;
+pub const CAN_CTRLMODE_CC_LEN8_DLC: u32 = 1 << 8;
+
+/// The hardware bit-timing limits of a CAN controller, as reported by the kernel's
+/// `struct can_bittiming_const`, along with the controller's clock frequency.
+#[derive(Debug, Clone)]
+pub struct CanBitTimingConst {
+    pub name: String,
+    pub tseg1_min: u32,
+    pub tseg1_max: u32,
+    pub tseg2_min: u32,
+    pub tseg2_max: u32,
+    pub sjw_max: u32,
+    pub brp_min: u32,
+    pub brp_max: u32,
+    pub brp_inc: u32,
+    /// The controller clock frequency in Hz.
+    pub clock: u32,
+}
+
 // These are missing from libc and neli, adding them here as a stand-in for now.
 mod rt {
     use libc::{c_uint, c_ushort};
@@ -98,6 +169,12 @@ mod rt {
     #[allow(unused)]
     pub const EXT_FILTER_MST: c_uint = 1 << 7;
 
+    /// The `RTNLGRP_LINK` multicast group, from linux/rtnetlink.h. Subscribing a
+    /// route socket to this group delivers `Rtm::Newlink`/`Rtm::Dellink` notifications
+    /// whenever any interface's state changes.
+    #[allow(unused)]
+    pub const RTNLGRP_LINK: u32 = 1;
+
     ////
     /// Also currently missing from libc, from linux/can/netlink.h:
     ///
@@ -121,6 +198,78 @@ mod rt {
         pub(crate) brp: u32,          /* Bit-rate prescaler */
     }
 
+    /// Currently missing from libc, from linux/can/netlink.h:
+    ///
+    /// CAN control mode
+    ///
+    /// `mask` selects which bits of `flags` are to be changed, `flags` holds the
+    /// desired values for those bits. See the `CAN_CTRLMODE_*` constants in the
+    /// parent module.
+    #[repr(C)]
+    #[allow(non_camel_case_types)]
+    #[derive(Default, Clone, Copy)]
+    pub struct can_ctrlmode {
+        pub(crate) mask: u32,
+        pub(crate) flags: u32,
+    }
+
+    /// Currently missing from libc, from linux/can/netlink.h:
+    ///
+    /// CAN bus error counters
+    #[repr(C)]
+    #[allow(non_camel_case_types)]
+    #[derive(Default, Clone, Copy)]
+    pub struct can_berr_counter {
+        pub(crate) txerr: u16,
+        pub(crate) rxerr: u16,
+    }
+
+    /// Currently missing from libc, from linux/can/netlink.h:
+    ///
+    /// CAN hardware-dependent bit-timing constants, as reported by the controller's
+    /// driver.
+    #[repr(C)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Copy)]
+    pub struct can_bittiming_const {
+        pub(crate) name: [u8; 16],
+        pub(crate) tseg1_min: u32,
+        pub(crate) tseg1_max: u32,
+        pub(crate) tseg2_min: u32,
+        pub(crate) tseg2_max: u32,
+        pub(crate) sjw_max: u32,
+        pub(crate) brp_min: u32,
+        pub(crate) brp_max: u32,
+        pub(crate) brp_inc: u32,
+    }
+
+    /// Reinterprets the payload of a netlink attribute as the given `repr(C)` struct.
+    ///
+    /// Used for the handful of CAN-specific structs (`can_ctrlmode`, `can_berr_counter`, ...)
+    /// that are not netlink types themselves, but are nested inside one as raw bytes.
+    pub(crate) fn read_struct<T: Copy>(bytes: &[u8]) -> Option<T> {
+        if bytes.len() != size_of::<T>() {
+            return None;
+        }
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                value.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+            Some(value.assume_init())
+        }
+    }
+
+    /// The inverse of `read_struct`: copies a `repr(C)` struct out into its raw bytes,
+    /// ready to be used as the payload of a netlink attribute.
+    pub(crate) fn struct_bytes<T>(value: &T) -> Vec<u8> {
+        unsafe {
+            std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()).to_vec()
+        }
+    }
+
     /// Currently missing from libc, from linux/can/netlink.h:
     ///
     /// CAN netlink interface
@@ -225,6 +374,49 @@ impl CanInterface {
         }
     }
 
+    /// Builds the `IFLA_LINKINFO` attribute wrapping a "can" `IFLA_INFO_DATA` attribute
+    /// populated with the given `IflaCan` sub-attributes. Used by all the `set_*`
+    /// methods that configure the interface via the kernel's CAN netlink interface.
+    fn build_can_info(attrs: Vec<(rt::IflaCan, Vec<u8>)>) -> NlResult<Rtattr<Ifla, Buffer>> {
+        let mut link_info = Rtattr::new(None, Ifla::Linkinfo, Buffer::new())?;
+        link_info.add_nested_attribute(&Rtattr::new(None, IflaInfo::Kind, "can")?)?;
+        let mut data = Rtattr::new(None, IflaInfo::Data, Buffer::new())?;
+        for (attr_type, payload) in attrs {
+            data.add_nested_attribute(&Rtattr::new(None, attr_type, payload)?)?;
+        }
+        link_info.add_nested_attribute(&data)?;
+        Ok(link_info)
+    }
+
+    /// Walks the `Ifla::Linkinfo` -> `IflaInfo::Data` nested attributes and returns the
+    /// CAN-specific (`IflaCan`) sub-attributes found within, as their raw `(type,
+    /// payload bytes)` pairs. Used by every method that decodes CAN netlink attributes
+    /// out of a `Getlink` response.
+    fn can_attrs(rtattrs: &RtBuffer<Ifla, Buffer>) -> Vec<(rt::IflaCan, Vec<u8>)> {
+        let mut result = Vec::new();
+        for attr in rtattrs.iter() {
+            if attr.rta_type == Ifla::Linkinfo {
+                if let Ok(link_info) = attr.get_nested_attributes::<IflaInfo>() {
+                    for link_info_attr in link_info.iter() {
+                        if link_info_attr.rta_type == IflaInfo::Data {
+                            if let Ok(can_attrs) =
+                                link_info_attr.get_nested_attributes::<rt::IflaCan>()
+                            {
+                                for can_attr in can_attrs.iter() {
+                                    result.push((
+                                        can_attr.rta_type,
+                                        can_attr.rta_payload.as_ref().to_vec(),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
     /// Opens a new netlink socket, bound to this process' PID.
     /// The function is generic to allow for usage in contexts where NlError has specific,
     /// non-default generic parameters.
@@ -357,6 +549,11 @@ impl CanInterface {
                     index: self.if_index,
                     is_up: false,
                     mtu: None,
+                    ctrlmode: None,
+                    state: None,
+                    error_counters: None,
+                    restart_ms: None,
+                    termination: None,
                 };
 
                 if let Ok(payload) = msg_hdr.get_payload() {
@@ -374,24 +571,44 @@ impl CanInterface {
                                 }
                             }
                             Ifla::Mtu => {
-                                if attr.rta_payload.len() == 4 {
-                                    let mut bytes = [0u8; 4];
-                                    for (index, byte) in
-                                        attr.rta_payload.as_ref().iter().enumerate()
-                                    {
-                                        bytes[index] = *byte;
-                                    }
+                                const STANDARD: u32 = Mtu::Standard as u32;
+                                const FD: u32 = Mtu::Fd as u32;
 
-                                    const STANDARD: u32 = Mtu::Standard as u32;
-                                    const FD: u32 = Mtu::Fd as u32;
+                                info.mtu = match rt::read_struct::<u32>(attr.rta_payload.as_ref()) {
+                                    Some(STANDARD) => Some(Mtu::Standard),
+                                    Some(FD) => Some(Mtu::Fd),
+                                    _ => None,
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
 
-                                    info.mtu = match u32::from_ne_bytes(bytes) {
-                                        STANDARD => Some(Mtu::Standard),
-                                        FD => Some(Mtu::Fd),
-                                        _ => None,
-                                    }
+                    for (attr_type, bytes) in Self::can_attrs(&payload.rtattrs) {
+                        match attr_type {
+                            rt::IflaCan::CtrlMode => {
+                                if let Some(ctrlmode) = rt::read_struct::<rt::can_ctrlmode>(&bytes)
+                                {
+                                    info.ctrlmode = Some(ctrlmode.flags);
+                                }
+                            }
+                            rt::IflaCan::State => {
+                                info.state =
+                                    rt::read_struct::<u32>(&bytes).and_then(CanState::from_u32);
+                            }
+                            rt::IflaCan::RestartMs => {
+                                info.restart_ms = rt::read_struct::<u32>(&bytes);
+                            }
+                            rt::IflaCan::BerrCounter => {
+                                if let Some(counters) =
+                                    rt::read_struct::<rt::can_berr_counter>(&bytes)
+                                {
+                                    info.error_counters = Some((counters.txerr, counters.rxerr));
                                 }
                             }
+                            rt::IflaCan::Termination => {
+                                info.termination = rt::read_struct::<u16>(&bytes);
+                            }
                             _ => (),
                         }
                     }
@@ -444,10 +661,118 @@ impl CanInterface {
             IffFlags::empty(),
             IffFlags::empty(),
             {
+                let timing = can_bittiming {
+                    bitrate,
+                    sample_point: sample_point.unwrap_or(0) as u32,
+                    tq: 0,
+                    prop_seg: 0,
+                    phase_seg1: 0,
+                    phase_seg2: 0,
+                    sjw: 0,
+                    brp: 0,
+                };
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::BitTiming,
+                    rt::struct_bytes(&timing),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// PRIVILEGED: Attempt to set the data-phase bitrate (and optionally sample point)
+    /// of this interface. This only has an effect on CAN FD capable interfaces, since
+    /// classic CAN frames have no separate data phase; the MTU is therefore switched to
+    /// `Mtu::Fd` first if it isn't already.
+    pub fn set_data_bitrate(&self, bitrate: u32, sample_point: Option<u16>) -> NlResult<()> {
+        debug_assert!(
+            0 < bitrate && bitrate <= 8000000,
+            "Data bitrate must be within 1..=8000000, received {}.",
+            bitrate
+        );
+        debug_assert!(
+            sample_point.filter(|point| *point >= 1000u16).is_none(),
+            "Sample point must be within 0..1000, received {}.",
+            sample_point.unwrap()
+        );
+
+        self.set_mtu(Mtu::Fd)?;
+
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let timing = can_bittiming {
+                    bitrate,
+                    sample_point: sample_point.unwrap_or(0) as u32,
+                    tq: 0,
+                    prop_seg: 0,
+                    phase_seg1: 0,
+                    phase_seg2: 0,
+                    sjw: 0,
+                    brp: 0,
+                };
                 let mut buffer = RtBuffer::new();
-                let mut link_info = Rtattr::new(None, Ifla::Linkinfo, Buffer::new())?;
-                link_info.add_nested_attribute(&Rtattr::new(None, IflaInfo::Kind, "can")?)?;
-                let mut data = Rtattr::new(None, IflaInfo::Data, Buffer::new())?;
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::DataBitTiming,
+                    rt::struct_bytes(&timing),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// PRIVILEGED: Attempt to set both the arbitration- and data-phase bitrate of this
+    /// interface in a single netlink message. Equivalent to calling `set_bitrate` and
+    /// `set_data_bitrate` individually, except both `can_bittiming` structs are sent as
+    /// part of the same `Rtm::Newlink` request. The MTU is switched to `Mtu::Fd` first
+    /// if it isn't already, since data bittiming is only meaningful on FD-capable
+    /// interfaces.
+    pub fn set_fd_bitrates(
+        &self,
+        bitrate: u32,
+        sample_point: Option<u16>,
+        data_bitrate: u32,
+        data_sample_point: Option<u16>,
+    ) -> NlResult<()> {
+        debug_assert!(
+            0 < bitrate && bitrate <= 1000000,
+            "Bitrate must be within 1..=1000000, received {}.",
+            bitrate
+        );
+        debug_assert!(
+            0 < data_bitrate && data_bitrate <= 8000000,
+            "Data bitrate must be within 1..=8000000, received {}.",
+            data_bitrate
+        );
+        debug_assert!(
+            sample_point.filter(|point| *point >= 1000u16).is_none(),
+            "Sample point must be within 0..1000, received {}.",
+            sample_point.unwrap()
+        );
+        debug_assert!(
+            data_sample_point
+                .filter(|point| *point >= 1000u16)
+                .is_none(),
+            "Data sample point must be within 0..1000, received {}.",
+            data_sample_point.unwrap()
+        );
+
+        self.set_mtu(Mtu::Fd)?;
+
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
                 let timing = can_bittiming {
                     bitrate,
                     sample_point: sample_point.unwrap_or(0) as u32,
@@ -458,18 +783,481 @@ impl CanInterface {
                     sjw: 0,
                     brp: 0,
                 };
-                data.add_nested_attribute(&Rtattr::new(None, rt::IflaCan::BitTiming, unsafe {
-                    std::slice::from_raw_parts::<'_, u8>(
-                        &timing as *const can_bittiming as *const u8,
-                        size_of::<can_bittiming>(),
-                    )
-                })?)?;
-                buffer.push(link_info);
+                let data_timing = can_bittiming {
+                    bitrate: data_bitrate,
+                    sample_point: data_sample_point.unwrap_or(0) as u32,
+                    tq: 0,
+                    prop_seg: 0,
+                    phase_seg1: 0,
+                    phase_seg2: 0,
+                    sjw: 0,
+                    brp: 0,
+                };
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![
+                    (rt::IflaCan::BitTiming, rt::struct_bytes(&timing)),
+                    (rt::IflaCan::DataBitTiming, rt::struct_bytes(&data_timing)),
+                ])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// PRIVILEGED: Attempt to set the given `can_ctrlmode` flags of this interface.
+    ///
+    /// `mask` selects which of the `CAN_CTRLMODE_*` bits are to be changed, `flags`
+    /// holds the desired values for those bits. For example, passing the same value
+    /// for both, built from `CAN_CTRLMODE_LOOPBACK | CAN_CTRLMODE_LISTENONLY`, enables
+    /// loopback and listen-only mode while leaving everything else untouched.
+    pub fn set_ctrlmode(&self, mask: u32, flags: u32) -> NlResult<()> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let ctrlmode = rt::can_ctrlmode { mask, flags };
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::CtrlMode,
+                    rt::struct_bytes(&ctrlmode),
+                )])?);
                 buffer
             },
         );
         Self::send_info_msg(Rtm::Newlink, info, &[])
     }
+
+    /// PRIVILEGED: Attempt to configure the automatic restart delay of this interface.
+    ///
+    /// Once the bus goes into the bus-off state, the interface will automatically
+    /// restart itself after `ms` milliseconds. Passing `0` disables automatic restart,
+    /// requiring a manual [`restart`](Self::restart) instead.
+    pub fn set_restart_ms(&self, ms: u32) -> NlResult<()> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::RestartMs,
+                    u32::to_ne_bytes(ms).to_vec(),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// PRIVILEGED: Attempt to manually restart the interface after it has gone into the
+    /// bus-off state. This has no effect unless the interface is currently bus-off.
+    pub fn restart(&self) -> NlResult<()> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::Restart,
+                    u32::to_ne_bytes(1).to_vec(),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// Attempt to query this interface's hardware bit-timing constants, i.e. the
+    /// limits the controller's driver imposes on the `can_bittiming` parameters, along
+    /// with the controller's clock frequency.
+    pub fn bittiming_const(&self) -> Result<CanBitTimingConst, NlError<Rtm, Ifinfomsg>> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            RtBuffer::new(),
+        );
+
+        let mut nl = Self::open_route_socket()?;
+
+        let hdr = Nlmsghdr::new(
+            None,
+            Rtm::Getlink,
+            NlmFFlags::new(&[NlmF::Request]),
+            None,
+            None,
+            NlPayload::Payload(info),
+        );
+        nl.send(hdr)?;
+
+        let mut bittiming_const = None;
+        let mut clock = None;
+
+        if let Some(msg_hdr) = nl.recv::<'_, Rtm, Ifinfomsg>()? {
+            if let Ok(payload) = msg_hdr.get_payload() {
+                for (attr_type, bytes) in Self::can_attrs(&payload.rtattrs) {
+                    match attr_type {
+                        rt::IflaCan::BitTimingConst => {
+                            bittiming_const = rt::read_struct::<rt::can_bittiming_const>(&bytes);
+                        }
+                        rt::IflaCan::Clock => {
+                            clock = rt::read_struct::<u32>(&bytes);
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        match (bittiming_const, clock) {
+            (Some(btc), Some(clock)) => Ok(CanBitTimingConst {
+                name: String::from_utf8_lossy(&btc.name)
+                    .trim_end_matches('\0')
+                    .to_string(),
+                tseg1_min: btc.tseg1_min,
+                tseg1_max: btc.tseg1_max,
+                tseg2_min: btc.tseg2_min,
+                tseg2_max: btc.tseg2_max,
+                sjw_max: btc.sjw_max,
+                brp_min: btc.brp_min,
+                brp_max: btc.brp_max,
+                brp_inc: btc.brp_inc,
+                clock,
+            }),
+            _ => Err(NlError::Msg(
+                "Interface did not report hardware bit-timing constants".to_string(),
+            )),
+        }
+    }
+
+    /// PRIVILEGED: Attempt to set the bitrate (and sample point) of this interface by
+    /// running the Bosch bit-timing solver locally against the hardware's
+    /// [`bittiming_const`](Self::bittiming_const), rather than letting the kernel pick
+    /// arbitrary timing. This gives deterministic, reproducible timing across kernels
+    /// and driver versions.
+    pub fn set_bitrate_precise(&self, bitrate: u32, sample_point: u16) -> NlResult<()> {
+        debug_assert!(
+            sample_point < 1000,
+            "Sample point must be within 0..1000, received {}.",
+            sample_point
+        );
+
+        if bitrate == 0 {
+            return Err(NlError::Msg("Bitrate must be greater than 0".to_string()));
+        }
+
+        let hw = self.bittiming_const().map_err(|err| {
+            NlError::Msg(format!(
+                "Could not read hardware bit-timing constants: {:?}",
+                err
+            ))
+        })?;
+
+        if hw.brp_inc == 0 || hw.brp_max < hw.brp_min {
+            return Err(NlError::Msg(format!(
+                "Hardware reported a degenerate brp range (min {}, max {}, inc {}); refusing to search it",
+                hw.brp_min, hw.brp_max, hw.brp_inc
+            )));
+        }
+
+        let (brp, tseg1, tseg2, sjw) = Self::solve_bit_timing(&hw, bitrate, sample_point)
+            .ok_or_else(|| {
+                NlError::Msg(
+                    "No feasible bit-timing found for the given bitrate/sample point".to_string(),
+                )
+            })?;
+
+        // The kernel only takes explicit segments when `tq` is set and `bitrate` is 0;
+        // with `bitrate` set and `tq` 0 it would calculate its own timing instead. It
+        // derives bitrate and sample point from the segments itself.
+        let tq = ((brp as u64 * 1_000_000_000 + hw.clock as u64 / 2) / hw.clock as u64) as u32;
+        let timing = can_bittiming {
+            bitrate: 0,
+            sample_point: 0,
+            tq,
+            prop_seg: 0,
+            phase_seg1: tseg1,
+            phase_seg2: tseg2,
+            sjw,
+            brp,
+        };
+
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::BitTiming,
+                    rt::struct_bytes(&timing),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+
+    /// Runs the Bosch bit-timing solver over `hw.brp_min..=hw.brp_max` and returns the
+    /// `(brp, tseg1, tseg2, sjw)` quadruplet that gets closest to `bitrate`/`sample_point`,
+    /// or `None` if none of them produces a usable `btq`. Assumes `hw.brp_inc > 0` and
+    /// `hw.brp_max >= hw.brp_min`; callers are responsible for validating that first.
+    fn solve_bit_timing(
+        hw: &CanBitTimingConst,
+        bitrate: u32,
+        sample_point: u16,
+    ) -> Option<(u32, u32, u32, u32)> {
+        // (brp, tseg1, tseg2, bitrate_error, sample_point_error)
+        let mut best: Option<(u32, u32, u32, u32, u32)> = None;
+
+        let mut brp = hw.brp_min;
+        while brp <= hw.brp_max {
+            if brp == 0 {
+                brp += hw.brp_inc;
+                continue;
+            }
+
+            let btq = hw.clock / (brp * bitrate);
+            if btq == 0 {
+                brp += hw.brp_inc;
+                continue;
+            }
+            let tseg = btq - 1;
+
+            let raw_tseg1 = ((sample_point as u64 * btq as u64 + 500) / 1000) as i64 - 1;
+            let tseg1 = raw_tseg1.clamp(hw.tseg1_min as i64, hw.tseg1_max as i64) as u32;
+            let raw_tseg2 = tseg as i64 - tseg1 as i64;
+            let tseg2 = raw_tseg2.clamp(hw.tseg2_min as i64, hw.tseg2_max as i64) as u32;
+
+            let actual_bitrate = hw.clock / (brp * (tseg1 + tseg2 + 1));
+            let actual_sample_point = (tseg1 + 1) * 1000 / (tseg1 + tseg2 + 1);
+
+            let bitrate_error = actual_bitrate.abs_diff(bitrate);
+            let sample_point_error = actual_sample_point.abs_diff(sample_point as u32);
+
+            let is_better = match best {
+                Some((_, _, _, best_bitrate_error, best_sample_point_error)) => {
+                    (bitrate_error, sample_point_error)
+                        < (best_bitrate_error, best_sample_point_error)
+                }
+                None => true,
+            };
+            if is_better {
+                best = Some((brp, tseg1, tseg2, bitrate_error, sample_point_error));
+            }
+
+            brp += hw.brp_inc;
+        }
+
+        best.map(|(brp, tseg1, tseg2, ..)| {
+            let sjw = std::cmp::min(hw.sjw_max, (tseg2 + 1) / 2);
+            (brp, tseg1, tseg2, sjw)
+        })
+    }
+
+    /// Attempt to query the termination resistances supported by this interface's
+    /// controller, in Ohm (e.g. `[0, 120]`). Empty if the controller does not support
+    /// switching termination at all.
+    pub fn termination_const(&self) -> Result<Vec<u16>, NlError<Rtm, Ifinfomsg>> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            RtBuffer::new(),
+        );
+
+        let mut nl = Self::open_route_socket()?;
+
+        let hdr = Nlmsghdr::new(
+            None,
+            Rtm::Getlink,
+            NlmFFlags::new(&[NlmF::Request]),
+            None,
+            None,
+            NlPayload::Payload(info),
+        );
+        nl.send(hdr)?;
+
+        let mut termination_const = Vec::new();
+
+        if let Some(msg_hdr) = nl.recv::<'_, Rtm, Ifinfomsg>()? {
+            if let Ok(payload) = msg_hdr.get_payload() {
+                for (attr_type, bytes) in Self::can_attrs(&payload.rtattrs) {
+                    if attr_type == rt::IflaCan::TerminationConst {
+                        termination_const = bytes
+                            .chunks_exact(2)
+                            .map(|chunk| u16::from_ne_bytes([chunk[0], chunk[1]]))
+                            .collect();
+                    }
+                }
+            }
+        }
+
+        Ok(termination_const)
+    }
+
+    /// PRIVILEGED: Attempt to set the termination resistance of this interface, in
+    /// Ohm. The value must be one reported by [`termination_const`](Self::termination_const).
+    pub fn set_termination(&self, ohms: u16) -> NlResult<()> {
+        let info = Ifinfomsg::new(
+            RtAddrFamily::Unspecified,
+            Arphrd::Netrom,
+            self.if_index as c_int,
+            IffFlags::empty(),
+            IffFlags::empty(),
+            {
+                let mut buffer = RtBuffer::new();
+                buffer.push(Self::build_can_info(vec![(
+                    rt::IflaCan::Termination,
+                    u16::to_ne_bytes(ohms).to_vec(),
+                )])?);
+                buffer
+            },
+        );
+        Self::send_info_msg(Rtm::Newlink, info, &[])
+    }
+}
+
+/// A CAN interface state-change event, as delivered by [`CanInterfaceMonitor`].
+#[derive(Debug, Clone)]
+pub struct CanInterfaceEvent {
+    pub if_index: c_uint,
+    pub name: Option<String>,
+    pub is_up: bool,
+    pub state: Option<CanState>,
+}
+
+/// Subscribes to the kernel's `RTNLGRP_LINK` netlink multicast group to deliver CAN
+/// interface state-change events (up/down, MTU, bus-off, ...) as they happen, instead
+/// of having to poll via [`CanInterface::details`].
+pub struct CanInterfaceMonitor {
+    socket: NlSocketHandle,
+    if_index: Option<c_uint>,
+}
+
+impl CanInterfaceMonitor {
+    /// Open a monitor that receives events for every interface on the system.
+    pub fn new() -> Result<Self, NlError> {
+        // Unlike the short-lived sockets `open_route_socket` hands out, this socket is held
+        // open for the lifetime of the monitor. Binding it to our own pid would race with any
+        // other socket opened from this process while a monitor is alive, so let the kernel
+        // assign a unique port id instead.
+        let socket = NlSocketHandle::connect(NlFamily::Route, None, &[rt::RTNLGRP_LINK])?;
+        Ok(Self {
+            socket,
+            if_index: None,
+        })
+    }
+
+    /// Open a monitor that only yields events for the given interface.
+    pub fn for_interface(interface: &CanInterface) -> Result<Self, NlError> {
+        let mut monitor = Self::new()?;
+        monitor.if_index = Some(interface.if_index);
+        Ok(monitor)
+    }
+
+    /// Blocks until the next matching interface event is received.
+    pub fn next_event(&mut self) -> Result<CanInterfaceEvent, NlError<Rtm, Ifinfomsg>> {
+        loop {
+            if let Some(msg_hdr) = self.socket.recv::<'_, Rtm, Ifinfomsg>()? {
+                if let Ok(payload) = msg_hdr.get_payload() {
+                    let event = CanInterfaceEvent {
+                        if_index: payload.ifi_index as c_uint,
+                        is_up: payload.ifi_flags.contains(&Iff::Up),
+                        name: None,
+                        state: None,
+                    };
+
+                    if let Some(if_index) = self.if_index {
+                        if if_index != event.if_index {
+                            continue;
+                        }
+                    }
+
+                    return Ok(Self::fill_in_attributes(event, &payload.rtattrs));
+                }
+            }
+        }
+    }
+
+    /// Decodes `Ifla::Ifname` and the CAN `IflaCan::State` attribute, the same way
+    /// [`CanInterface::details`] does, filling in the rest of the event.
+    fn fill_in_attributes(
+        mut event: CanInterfaceEvent,
+        rtattrs: &RtBuffer<Ifla, Buffer>,
+    ) -> CanInterfaceEvent {
+        for attr in rtattrs.iter() {
+            if attr.rta_type == Ifla::Ifname {
+                if let Ok(string) = CString::from_vec_with_nul(Vec::from(attr.rta_payload.as_ref()))
+                {
+                    if let Ok(string) = string.into_string() {
+                        event.name = Some(string);
+                    }
+                }
+            }
+        }
+
+        for (attr_type, bytes) in CanInterface::can_attrs(rtattrs) {
+            if attr_type == rt::IflaCan::State {
+                event.state = rt::read_struct::<u32>(&bytes).and_then(CanState::from_u32);
+            }
+        }
+
+        event
+    }
+}
+
+#[cfg(test)]
+mod bit_timing_tests {
+    use super::{CanBitTimingConst, CanInterface};
+
+    fn hw() -> CanBitTimingConst {
+        // Roughly an SJA1000-class controller: 8MHz clock, brp in 1..=64.
+        CanBitTimingConst {
+            name: "test".to_string(),
+            tseg1_min: 1,
+            tseg1_max: 16,
+            tseg2_min: 1,
+            tseg2_max: 8,
+            sjw_max: 4,
+            brp_min: 1,
+            brp_max: 64,
+            brp_inc: 1,
+            clock: 8_000_000,
+        }
+    }
+
+    #[test]
+    fn solves_known_good_timing() {
+        assert_eq!(
+            Some((1, 13, 2, 1)),
+            CanInterface::solve_bit_timing(&hw(), 500_000, 875)
+        );
+        assert_eq!(
+            Some((2, 12, 3, 2)),
+            CanInterface::solve_bit_timing(&hw(), 250_000, 800)
+        );
+    }
+
+    #[test]
+    fn no_solution_for_unreachable_bitrate() {
+        // Clock / brp_min is already lower than the requested bitrate, so no btq > 0 exists.
+        assert_eq!(None, CanInterface::solve_bit_timing(&hw(), 10_000_000, 500));
+    }
 }
 
 #[cfg(test)]
@@ -560,4 +1348,50 @@ pub mod tests {
         assert!(interface.set_mtu(Mtu::Standard).is_ok());
         assert_eq!(Mtu::Standard, interface.details().unwrap().mtu.unwrap());
     }
+
+    #[cfg(feature = "netlink_tests")]
+    #[test]
+    #[serial]
+    fn ctrlmode() {
+        // vcan only implements loopback mode, every other CAN_CTRLMODE_* flag is rejected.
+        let interface = TemporaryInterface::new("ctrlmode").unwrap();
+
+        assert!(interface
+            .set_ctrlmode(CAN_CTRLMODE_LOOPBACK, CAN_CTRLMODE_LOOPBACK)
+            .is_ok());
+        assert_eq!(
+            Some(CAN_CTRLMODE_LOOPBACK),
+            interface.details().unwrap().ctrlmode
+        );
+
+        assert!(interface.set_ctrlmode(CAN_CTRLMODE_LOOPBACK, 0).is_ok());
+        assert_eq!(Some(0), interface.details().unwrap().ctrlmode);
+    }
+
+    #[cfg(feature = "netlink_tests")]
+    #[test]
+    #[serial]
+    fn restart_ms() {
+        let interface = TemporaryInterface::new("restart_ms").unwrap();
+
+        assert!(interface.set_restart_ms(100).is_ok());
+        assert_eq!(Some(100), interface.details().unwrap().restart_ms);
+
+        assert!(interface.set_restart_ms(0).is_ok());
+        assert_eq!(Some(0), interface.details().unwrap().restart_ms);
+    }
+
+    #[cfg(feature = "netlink_tests")]
+    #[test]
+    #[serial]
+    fn monitor() {
+        let interface = TemporaryInterface::new("monitor").unwrap();
+        let mut monitor = CanInterfaceMonitor::for_interface(&interface).unwrap();
+
+        assert!(interface.bring_up().is_ok());
+
+        let event = monitor.next_event().unwrap();
+        assert_eq!(interface.if_index, event.if_index);
+        assert!(event.is_up);
+    }
 }